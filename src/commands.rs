@@ -1,45 +1,140 @@
 use tauri::command;
 
+#[cfg(target_os = "macos")]
+use std::sync::{Mutex, OnceLock};
+
+use serde::Deserialize;
+
 #[cfg(target_os = "macos")]
 use crate::haptics::*;
 
-/// Convert a u64 value to HapticPattern.
+/// Haptic feedback pattern accepted at the command boundary.
 ///
-/// This is used for the frontend API where patterns are passed as numbers.
-///
-/// # Mapping
-/// * `0` -> Alignment
-/// * `1` -> LevelChange
-/// * `2` or any other value -> Generic (default)
+/// Deserializes from either the string name (`"alignment"`, `"levelChange"`,
+/// `"generic"`, case-insensitive) or the legacy integer tag (`0`, `1`, `2`),
+/// keeping the existing numeric frontend encoding working. Any other value is
+/// rejected with a descriptive error instead of being silently coerced to a
+/// default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HapticFeedbackPattern {
+    /// `0` / `"alignment"`.
+    Alignment,
+    /// `1` / `"levelChange"`.
+    LevelChange,
+    /// `2` / `"generic"`.
+    Generic,
+}
+
+impl<'de> Deserialize<'de> for HapticFeedbackPattern {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+        match StringOrInt::deserialize(deserializer)? {
+            StringOrInt::Int(0) => Ok(Self::Alignment),
+            StringOrInt::Int(1) => Ok(Self::LevelChange),
+            StringOrInt::Int(2) => Ok(Self::Generic),
+            StringOrInt::Int(other) => Err(D::Error::custom(format!(
+                "unknown haptic pattern {other}; expected 0 (alignment), 1 (levelChange) or 2 (generic)"
+            ))),
+            StringOrInt::Str(name) => match name.to_ascii_lowercase().as_str() {
+                "alignment" => Ok(Self::Alignment),
+                "levelchange" => Ok(Self::LevelChange),
+                "generic" => Ok(Self::Generic),
+                other => Err(D::Error::custom(format!(
+                    "unknown haptic pattern \"{other}\"; expected \"alignment\", \"levelChange\" or \"generic\""
+                ))),
+            },
+        }
+    }
+}
+
 #[cfg(target_os = "macos")]
-fn pattern_from_u64(value: u64) -> HapticPattern {
-    match value {
-        0 => HapticPattern::Alignment,
-        1 => HapticPattern::LevelChange,
-        2 => HapticPattern::Generic,
-        _ => HapticPattern::Generic, // Default to Generic for unknown values
+impl HapticFeedbackPattern {
+    /// Converts to the underlying AppKit pattern.
+    fn into_objc(self) -> HapticPattern {
+        match self {
+            Self::Alignment => HapticPattern::Alignment,
+            Self::LevelChange => HapticPattern::LevelChange,
+            Self::Generic => HapticPattern::Generic,
+        }
     }
 }
 
-/// Convert a u64 value to PerformanceTime.
-///
-/// This is used for the frontend API where timing is passed as numbers.
+/// Performance time accepted at the command boundary.
 ///
-/// # Mapping
-/// * `0` -> Default (system decides)
-/// * `1` -> Now (immediate)
-/// * `2` -> DrawCompleted (after next screen update)
-/// * Any other value -> Default
+/// Deserializes from either the string name (`"default"`, `"now"`,
+/// `"drawCompleted"`, case-insensitive) or the legacy integer tag (`0`, `1`,
+/// `2`). Unknown values are rejected rather than defaulted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PerformanceTime {
+    /// `0` / `"default"`.
+    Default,
+    /// `1` / `"now"`.
+    Now,
+    /// `2` / `"drawCompleted"`.
+    DrawCompleted,
+}
+
+impl<'de> Deserialize<'de> for PerformanceTime {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+        match StringOrInt::deserialize(deserializer)? {
+            StringOrInt::Int(0) => Ok(Self::Default),
+            StringOrInt::Int(1) => Ok(Self::Now),
+            StringOrInt::Int(2) => Ok(Self::DrawCompleted),
+            StringOrInt::Int(other) => Err(D::Error::custom(format!(
+                "unknown performance time {other}; expected 0 (default), 1 (now) or 2 (drawCompleted)"
+            ))),
+            StringOrInt::Str(name) => match name.to_ascii_lowercase().as_str() {
+                "default" => Ok(Self::Default),
+                "now" => Ok(Self::Now),
+                "drawcompleted" => Ok(Self::DrawCompleted),
+                other => Err(D::Error::custom(format!(
+                    "unknown performance time \"{other}\"; expected \"default\", \"now\" or \"drawCompleted\""
+                ))),
+            },
+        }
+    }
+}
+
 #[cfg(target_os = "macos")]
-fn performance_time_from_u64(value: u64) -> PerformanceTime {
-    match value {
-        0 => PerformanceTime::Default,
-        1 => PerformanceTime::Now,
-        2 => PerformanceTime::DrawCompleted,
-        _ => PerformanceTime::Default, // Default for unknown values
+impl PerformanceTime {
+    /// Converts to the underlying AppKit performance time.
+    fn into_objc(self) -> crate::haptics::PerformanceTime {
+        match self {
+            Self::Default => crate::haptics::PerformanceTime::Default,
+            Self::Now => crate::haptics::PerformanceTime::Now,
+            Self::DrawCompleted => crate::haptics::PerformanceTime::DrawCompleted,
+        }
     }
 }
 
+/// Helper that accepts either an integer tag or a string name during
+/// deserialization, so command parameters stay backward compatible with the
+/// original numeric encoding.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StringOrInt {
+    Int(u64),
+    Str(String),
+}
+
+/// Handle to the sequence currently playing, if any.
+///
+/// Only one sequence runs at a time: starting a new one cancels whatever was
+/// already in flight. Guarded by a `Mutex` because commands may be invoked from
+/// any thread in Tauri's async runtime.
+#[cfg(target_os = "macos")]
+fn current_sequence() -> &'static Mutex<Option<SequenceHandle>> {
+    static CURRENT: OnceLock<Mutex<Option<SequenceHandle>>> = OnceLock::new();
+    CURRENT.get_or_init(|| Mutex::new(None))
+}
+
 /// Check if haptic feedback is supported on this system.
 ///
 /// Returns true on macOS 10.11+ systems with haptic-capable hardware.
@@ -61,12 +156,15 @@ pub async fn is_supported() -> bool {
 /// Perform haptic feedback with the specified pattern and timing.
 ///
 /// # Arguments
-/// * `pattern` - The haptic feedback pattern (0=Alignment, 1=LevelChange, 2=Generic)
-/// * `performance_time` - When to perform (0=Default, 1=Now, 2=DrawCompleted)
+/// * `pattern` - The haptic feedback pattern, as a name (`"alignment"`,
+///   `"levelChange"`, `"generic"`) or the legacy tag (`0`, `1`, `2`)
+/// * `performance_time` - When to perform, as a name (`"default"`, `"now"`,
+///   `"drawCompleted"`) or the legacy tag (`0`, `1`, `2`)
 ///
 /// # Returns
 /// * `Ok(())` - Feedback was performed successfully
-/// * `Err(String)` - An error occurred (with description)
+/// * `Err(String)` - An error occurred (with description). An unknown pattern
+///   or time is rejected at the JS/Rust boundary rather than silently defaulted
 ///
 /// # Platform Support
 /// * macOS: Fully supported on 10.11+ with haptic-capable hardware
@@ -74,22 +172,421 @@ pub async fn is_supported() -> bool {
 ///
 /// # Example (Frontend)
 /// ```typescript
-/// import { perform, HapticFeedbackPattern, PerformanceTime } from 'tauri-macos-haptics-api';
+/// import { perform } from 'tauri-macos-haptics-api';
 ///
-/// await perform(HapticFeedbackPattern.Generic, PerformanceTime.Now);
+/// await perform('generic', 'now');
+/// // The legacy numeric form still works:
+/// await perform(2, 1);
 /// ```
 #[command]
-pub async fn perform(pattern: u64, performance_time: u64) -> Result<(), String> {
+pub async fn perform(
+    pattern: HapticFeedbackPattern,
+    performance_time: PerformanceTime,
+) -> Result<(), String> {
     #[cfg(target_os = "macos")]
     {
         HapticFeedbackManager::default_performer()
-            .perform(
-                pattern_from_u64(pattern),
-                Some(performance_time_from_u64(performance_time)),
-            )
+            .perform(pattern.into_objc(), Some(performance_time.into_objc()))
             .map_err(|e| e.to_string())
     }
 
+    #[cfg(not(target_os = "macos"))]
+    {
+        let _ = (pattern, performance_time);
+        Err("Haptic feedback is only supported on macOS.".to_string())
+    }
+}
+
+/// A single step of a haptic sequence as received from the frontend.
+///
+/// Mirrors [`HapticStep`], but carries the pattern in the command-boundary
+/// encoding accepted by [`perform`] (a name or the legacy integer tag).
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SequenceStep {
+    /// Pattern to fire, as a name (`"generic"`) or the legacy tag (`0`, `1`,
+    /// `2`). Unknown values are rejected rather than defaulted.
+    pub pattern: HapticFeedbackPattern,
+    /// How long, in milliseconds, to sustain the pattern.
+    pub duration_ms: u64,
+    /// Silence, in milliseconds, to wait after the step.
+    pub gap_ms: u64,
+}
+
+/// Play a timed sequence of haptic patterns without blocking the UI.
+///
+/// Each step fires its pattern for `duration_ms` then pauses for `gap_ms`,
+/// making it easy to express progress ticks or morse-code-style feedback. Only
+/// one sequence runs at a time; calling this again cancels the previous one.
+/// Use [`cancel_sequence`] to stop the current sequence early.
+///
+/// # Example (Frontend)
+/// ```typescript
+/// import { performSequence, cancelSequence } from 'tauri-macos-haptics-api';
+///
+/// // "dot dash" in Generic taps
+/// await performSequence([
+///   { pattern: 2, durationMs: 60, gapMs: 120 },
+///   { pattern: 2, durationMs: 200, gapMs: 0 },
+/// ]);
+/// ```
+#[command]
+pub async fn perform_sequence(steps: Vec<SequenceStep>) -> Result<(), String> {
+    #[cfg(target_os = "macos")]
+    {
+        let haptic_steps: Vec<HapticStep> = steps
+            .iter()
+            .map(|step| HapticStep {
+                pattern: step.pattern.into_objc(),
+                duration_ms: step.duration_ms,
+                gap_ms: step.gap_ms,
+            })
+            .collect();
+
+        // Hold the lock across the whole swap so only one sequence is ever
+        // firing: cancel the previous sequence *before* spawning the new one,
+        // otherwise both would briefly fire taps concurrently.
+        let mut current = current_sequence().lock().unwrap();
+        if let Some(previous) = current.take() {
+            previous.cancel();
+        }
+        let handle = HapticFeedbackManager::default_performer().play_sequence(&haptic_steps);
+        *current = Some(handle);
+        Ok(())
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    {
+        let _ = steps;
+        Err("Haptic feedback is only supported on macOS.".to_string())
+    }
+}
+
+/// Load and play an Apple Haptic Audio Pattern (AHAP).
+///
+/// Accepts either a filesystem path to an `.ahap` file or an inline AHAP JSON
+/// document — a value whose first non-whitespace character is `{` is treated as
+/// inline JSON, everything else as a path. Requires the CoreHaptics backend;
+/// check [`core_haptics_supported`] before calling.
+///
+/// # Returns
+/// * `Ok(())` - The pattern was played successfully
+/// * `Err(String)` - The file could not be read, was malformed, or the engine
+///   could not be started (with a description)
+///
+/// # Example (Frontend)
+/// ```typescript
+/// import { performAhap } from 'tauri-macos-haptics-api';
+///
+/// await performAhap('/path/to/Heartbeat.ahap');
+/// ```
+#[command]
+pub async fn perform_ahap(pattern: String) -> Result<(), String> {
+    #[cfg(target_os = "macos")]
+    {
+        // Engine creation and the keep-alive wait are synchronous and operate on
+        // `!Send` CoreHaptics objects, so run the whole thing on a blocking
+        // thread rather than parking a Tauri async-runtime worker.
+        tokio::task::spawn_blocking(move || {
+            let engine = crate::haptics::core::CoreHapticsEngine::new()?;
+            if pattern.trim_start().starts_with('{') {
+                engine.play_ahap_json(&pattern)
+            } else {
+                engine.play_ahap(&pattern)
+            }
+        })
+        .await
+        .map_err(|e| e.to_string())?
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    {
+        let _ = pattern;
+        Err("Haptic feedback is only supported on macOS.".to_string())
+    }
+}
+
+/// Check whether the CoreHaptics backend is available on this system.
+///
+/// Returns `true` only on macOS hardware that supports CoreHaptics; callers
+/// should fall back to [`perform`] otherwise.
+///
+/// # Example (Frontend)
+/// ```typescript
+/// import { coreHapticsSupported } from 'tauri-macos-haptics-api';
+///
+/// if (await coreHapticsSupported()) {
+///   // Safe to use performAhap / richer patterns
+/// }
+/// ```
+#[command]
+pub async fn core_haptics_supported() -> bool {
+    #[cfg(target_os = "macos")]
+    {
+        crate::haptics::core::is_core_haptics_supported()
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    {
+        false
+    }
+}
+
+/// Perform a high-level, semantic feedback event by name.
+///
+/// Accepts one of `selection`, `success`, `warning`, `error`, `alignment`,
+/// `levelChange`, or an impact weight (`impact`/`impactLight`/`impactMedium`/
+/// `impactHeavy`, where bare `impact` is medium). Matching is
+/// case-insensitive.
+///
+/// # Returns
+/// * `Ok(())` - The feedback was performed
+/// * `Err(String)` - The name was unknown, or the platform is unsupported
+///
+/// # Example (Frontend)
+/// ```typescript
+/// import { performSemantic } from 'tauri-macos-haptics-api';
+///
+/// await performSemantic('success');
+/// ```
+#[command]
+pub async fn perform_semantic(event: String) -> Result<(), String> {
+    #[cfg(target_os = "macos")]
+    {
+        let feedback = match event.to_ascii_lowercase().as_str() {
+            "selection" => SemanticFeedback::Selection,
+            "success" => SemanticFeedback::Success,
+            "warning" => SemanticFeedback::Warning,
+            "error" => SemanticFeedback::Error,
+            "alignment" => SemanticFeedback::Alignment,
+            "levelchange" => SemanticFeedback::LevelChange,
+            "impact" | "impactmedium" => SemanticFeedback::Impact {
+                weight: ImpactWeight::Medium,
+            },
+            "impactlight" => SemanticFeedback::Impact {
+                weight: ImpactWeight::Light,
+            },
+            "impactheavy" => SemanticFeedback::Impact {
+                weight: ImpactWeight::Heavy,
+            },
+            other => return Err(format!("unknown semantic feedback event \"{other}\"")),
+        };
+
+        // The CoreHaptics rendering keeps its engine alive across a synchronous
+        // wait, so play on a blocking thread to avoid starving the async runtime.
+        tokio::task::spawn_blocking(move || {
+            HapticFeedbackManager::default_performer()
+                .perform_semantic(feedback)
+                .map_err(|e| e.to_string())
+        })
+        .await
+        .map_err(|e| e.to_string())?
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    {
+        let _ = event;
+        Err("Haptic feedback is only supported on macOS.".to_string())
+    }
+}
+
+/// Check whether any connected game controller can play haptics.
+///
+/// Returns `true` only on macOS 11+ with a connected, haptics-capable
+/// controller.
+///
+/// # Example (Frontend)
+/// ```typescript
+/// import { controllerHapticsSupported } from 'tauri-macos-haptics-api';
+///
+/// if (await controllerHapticsSupported()) {
+///   // A controller is connected and can rumble
+/// }
+/// ```
+#[command]
+pub async fn controller_haptics_supported() -> bool {
+    #[cfg(target_os = "macos")]
+    {
+        controller::controller_haptics_supported()
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    {
+        false
+    }
+}
+
+/// List the currently connected game controllers.
+///
+/// Each entry carries an `id` to pass to [`play_controller_haptic`], a
+/// human-readable `name`, and whether it `supportsHaptics`.
+///
+/// # Example (Frontend)
+/// ```typescript
+/// import { listControllers } from 'tauri-macos-haptics-api';
+///
+/// const controllers = await listControllers();
+/// ```
+#[command]
+pub async fn list_controllers() -> Result<Vec<crate::ControllerInfo>, String> {
+    #[cfg(target_os = "macos")]
+    {
+        Ok(controller::list_controllers())
+    }
+
     #[cfg(not(target_os = "macos"))]
     Err("Haptic feedback is only supported on macOS.".to_string())
 }
+
+/// Play rumble on a connected game controller.
+///
+/// # Arguments
+/// * `controller_id` - An `id` from [`list_controllers`]
+/// * `intensity` - Strength, `0.0..=1.0`
+/// * `sharpness` - Crispness, `0.0..=1.0`
+/// * `duration_ms` - How long to rumble, in milliseconds
+/// * `locality` - Which engine to drive (`default`, `all`, `leftHandle`,
+///   `rightHandle`, `leftTrigger`, `rightTrigger`)
+///
+/// # Example (Frontend)
+/// ```typescript
+/// import { playControllerHaptic } from 'tauri-macos-haptics-api';
+///
+/// await playControllerHaptic('0', 1.0, 0.5, 300, 'default');
+/// ```
+#[command]
+pub async fn play_controller_haptic(
+    controller_id: String,
+    intensity: f32,
+    sharpness: f32,
+    duration_ms: u64,
+    locality: String,
+) -> Result<(), String> {
+    #[cfg(target_os = "macos")]
+    {
+        // Rumble keeps the controller's engine alive for `duration_ms` on a
+        // synchronous wait, so run it on a blocking thread rather than a Tauri
+        // async-runtime worker.
+        tokio::task::spawn_blocking(move || {
+            controller::play_controller_haptic(
+                &controller_id,
+                intensity,
+                sharpness,
+                duration_ms,
+                &locality,
+            )
+        })
+        .await
+        .map_err(|e| e.to_string())?
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    {
+        let _ = (controller_id, intensity, sharpness, duration_ms, locality);
+        Err("Haptic feedback is only supported on macOS.".to_string())
+    }
+}
+
+/// Cancel the haptic sequence currently playing, if any.
+///
+/// Safe to call when no sequence is running.
+#[command]
+pub async fn cancel_sequence() -> Result<(), String> {
+    #[cfg(target_os = "macos")]
+    {
+        if let Some(handle) = current_sequence().lock().unwrap().take() {
+            handle.cancel();
+        }
+        Ok(())
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    Err("Haptic feedback is only supported on macOS.".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pattern(json: &str) -> Result<HapticFeedbackPattern, String> {
+        serde_json::from_str(json).map_err(|e| e.to_string())
+    }
+
+    fn time(json: &str) -> Result<PerformanceTime, String> {
+        serde_json::from_str(json).map_err(|e| e.to_string())
+    }
+
+    #[test]
+    fn pattern_accepts_integer_tags() {
+        assert_eq!(pattern("0").unwrap(), HapticFeedbackPattern::Alignment);
+        assert_eq!(pattern("1").unwrap(), HapticFeedbackPattern::LevelChange);
+        assert_eq!(pattern("2").unwrap(), HapticFeedbackPattern::Generic);
+    }
+
+    #[test]
+    fn pattern_accepts_names_case_insensitively() {
+        assert_eq!(
+            pattern("\"alignment\"").unwrap(),
+            HapticFeedbackPattern::Alignment
+        );
+        assert_eq!(
+            pattern("\"levelChange\"").unwrap(),
+            HapticFeedbackPattern::LevelChange
+        );
+        assert_eq!(
+            pattern("\"GENERIC\"").unwrap(),
+            HapticFeedbackPattern::Generic
+        );
+    }
+
+    #[test]
+    fn pattern_rejects_out_of_range_tag() {
+        let err = pattern("7").unwrap_err();
+        assert!(err.contains("unknown haptic pattern 7"), "{err}");
+    }
+
+    #[test]
+    fn pattern_rejects_unknown_name() {
+        let err = pattern("\"wiggle\"").unwrap_err();
+        assert!(err.contains("unknown haptic pattern \"wiggle\""), "{err}");
+    }
+
+    #[test]
+    fn performance_time_accepts_both_encodings() {
+        assert_eq!(time("1").unwrap(), PerformanceTime::Now);
+        assert_eq!(
+            time("\"drawCompleted\"").unwrap(),
+            PerformanceTime::DrawCompleted
+        );
+    }
+
+    #[test]
+    fn performance_time_rejects_unknown_values() {
+        assert!(time("9").unwrap_err().contains("unknown performance time 9"));
+        assert!(
+            time("\"soon\"")
+                .unwrap_err()
+                .contains("unknown performance time \"soon\"")
+        );
+    }
+
+    #[test]
+    fn sequence_step_deserializes_name_or_tag() {
+        let step: SequenceStep =
+            serde_json::from_str(r#"{ "pattern": "generic", "durationMs": 60, "gapMs": 120 }"#)
+                .unwrap();
+        assert_eq!(step.pattern, HapticFeedbackPattern::Generic);
+        assert_eq!(step.duration_ms, 60);
+
+        let legacy: SequenceStep =
+            serde_json::from_str(r#"{ "pattern": 0, "durationMs": 10, "gapMs": 0 }"#).unwrap();
+        assert_eq!(legacy.pattern, HapticFeedbackPattern::Alignment);
+    }
+
+    #[test]
+    fn sequence_step_rejects_unknown_pattern() {
+        let result: Result<SequenceStep, _> =
+            serde_json::from_str(r#"{ "pattern": 42, "durationMs": 10, "gapMs": 0 }"#);
+        assert!(result.is_err());
+    }
+}
@@ -1,7 +1,25 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
 use objc2::rc::Retained;
 use objc2::runtime::ProtocolObject;
 use objc2_app_kit::{NSHapticFeedbackManager, NSHapticFeedbackPerformer};
 
+/// CoreHaptics backend for fine-grained intensity/sharpness control.
+///
+/// `NSHapticFeedbackManager` only exposes three canned patterns; the
+/// [`core`] module wraps `CHHapticEngine` so apps can play arbitrary
+/// transient and continuous waveforms when the hardware supports it.
+pub mod core;
+
+/// Game-controller haptics via the GameController framework.
+///
+/// The [`controller`] module drives rumble on connected controllers
+/// (DualSense, Xbox, MFi) through `GCController`/`GCHaptics`, extending the
+/// plugin beyond trackpad feedback.
+pub mod controller;
+
 // Re-export the types from objc2_app_kit for convenience
 pub use objc2_app_kit::NSHapticFeedbackPattern as HapticPattern;
 pub use objc2_app_kit::NSHapticFeedbackPerformanceTime as PerformanceTime;
@@ -77,4 +95,232 @@ impl HapticFeedbackManager {
             .performFeedbackPattern_performanceTime(pattern, ptime);
         Ok(())
     }
+
+    /// Plays a timed sequence of haptic patterns.
+    ///
+    /// `NSHapticFeedbackManager` only ever produces a momentary tap, so the
+    /// perceived "duration" of each step is synthesised by re-firing its
+    /// pattern every [`REFIRE_INTERVAL_MS`] milliseconds for the requested
+    /// `duration_ms`, then pausing for `gap_ms` before moving on. This makes it
+    /// easy to express progress ticks or morse-code-style feedback (a short
+    /// burst for a dot, a longer one for a dash) from the frontend.
+    ///
+    /// The sequence runs on a detached async task so the calling thread — and
+    /// the UI — is never blocked. The returned [`SequenceHandle`] can be used to
+    /// stop a sequence that is still in flight; dropping it does not cancel the
+    /// sequence.
+    ///
+    /// # IMPORTANT!
+    /// As with [`perform`](Self::perform), only start a sequence in response to a
+    /// user-initiated action and pair it with visual feedback.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use tauri_macos_haptics::haptics::*;
+    /// let manager = HapticFeedbackManager::default_performer();
+    /// // dot, dash
+    /// let handle = manager.play_sequence(&[
+    ///     HapticStep { pattern: HapticPattern::Generic, duration_ms: 60, gap_ms: 120 },
+    ///     HapticStep { pattern: HapticPattern::Generic, duration_ms: 200, gap_ms: 0 },
+    /// ]);
+    /// // Later, if needed:
+    /// handle.cancel();
+    /// ```
+    pub fn play_sequence(&self, steps: &[HapticStep]) -> SequenceHandle {
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let handle = SequenceHandle {
+            cancelled: Arc::clone(&cancelled),
+        };
+
+        let steps = steps.to_vec();
+        tauri::async_runtime::spawn(async move {
+            for step in steps {
+                if cancelled.load(Ordering::SeqCst) {
+                    return;
+                }
+
+                // Hold the pattern for `duration_ms` by re-firing it at a fixed
+                // interval. `fire` creates and drops its performer within the
+                // call so nothing Objective-C is held across an await point.
+                let mut elapsed = 0u64;
+                loop {
+                    if cancelled.load(Ordering::SeqCst) {
+                        return;
+                    }
+                    fire(step.pattern);
+                    // Stop once at most one more interval remains, so no tap
+                    // lands at or past the end of the requested span.
+                    if step.duration_ms.saturating_sub(elapsed) <= REFIRE_INTERVAL_MS {
+                        break;
+                    }
+                    tokio::time::sleep(Duration::from_millis(REFIRE_INTERVAL_MS)).await;
+                    elapsed += REFIRE_INTERVAL_MS;
+                }
+
+                if step.gap_ms > 0 {
+                    tokio::time::sleep(Duration::from_millis(step.gap_ms)).await;
+                }
+            }
+        });
+
+        handle
+    }
+
+    /// Performs a high-level, semantic feedback event.
+    ///
+    /// Rather than reasoning about raw patterns, app authors pick a meaning —
+    /// [`SemanticFeedback::Success`], [`SemanticFeedback::Selection`], and so
+    /// on — and this method plays the most expressive rendering the system can
+    /// manage. When the CoreHaptics backend is available it maps each event to
+    /// tuned transient/continuous events (including short multi-tap sequences
+    /// for Success/Warning/Error); otherwise it falls back to the closest
+    /// `NSHapticFeedbackManager` pattern.
+    ///
+    /// # IMPORTANT!
+    /// As with [`perform`](Self::perform), only trigger feedback in response to
+    /// a user-initiated action, ideally paired with visual feedback.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use tauri_macos_haptics::haptics::*;
+    /// let manager = HapticFeedbackManager::default_performer();
+    /// manager.perform_semantic(SemanticFeedback::Success)?;
+    /// # Ok::<(), tauri::Error>(())
+    /// ```
+    pub fn perform_semantic(&self, feedback: SemanticFeedback) -> Result<(), tauri::Error> {
+        // Prefer the richer CoreHaptics rendering when the hardware supports it,
+        // falling back to a single built-in pattern on failure or when
+        // unavailable. `play_semantic` keeps the engine and its player alive for
+        // the full span of the tap sequence, so the multi-tap Success/Warning/
+        // Error renderings play out in full before `engine` is dropped here.
+        if core::is_core_haptics_supported() {
+            if let Ok(engine) = core::CoreHapticsEngine::new() {
+                let played = engine.play_semantic(feedback).is_ok();
+                drop(engine);
+                if played {
+                    return Ok(());
+                }
+            }
+        }
+
+        self.perform(feedback.builtin_pattern(), Some(PerformanceTime::Now))
+    }
+}
+
+/// Interval, in milliseconds, at which a step's pattern is re-fired to
+/// synthesise a sustained haptic from the momentary taps that
+/// `NSHapticFeedbackManager` produces.
+pub const REFIRE_INTERVAL_MS: u64 = 40;
+
+/// Fire a single pattern immediately, discarding any error.
+///
+/// Kept free-standing so the sequence task never holds a non-`Send`
+/// [`Retained`] performer across an `await`.
+fn fire(pattern: HapticPattern) {
+    let _ = HapticFeedbackManager::default_performer().perform(pattern, Some(PerformanceTime::Now));
+}
+
+/// A single step in a haptic sequence played by [`HapticFeedbackManager::play_sequence`].
+///
+/// Each step fires `pattern` for roughly `duration_ms` (see [`REFIRE_INTERVAL_MS`]
+/// for how duration is approximated) and then waits `gap_ms` before the next step.
+#[derive(Debug, Clone, Copy)]
+pub struct HapticStep {
+    /// Pattern to fire for this step.
+    pub pattern: HapticPattern,
+    /// How long, in milliseconds, to sustain the pattern.
+    pub duration_ms: u64,
+    /// Silence, in milliseconds, to wait after the step before the next one.
+    pub gap_ms: u64,
+}
+
+/// Cancellation handle for a sequence started with
+/// [`HapticFeedbackManager::play_sequence`].
+///
+/// The handle is cheap to clone; every clone refers to the same sequence.
+/// Calling [`cancel`](Self::cancel) stops the sequence before its next tap.
+#[derive(Debug, Clone)]
+pub struct SequenceHandle {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl SequenceHandle {
+    /// Requests cancellation of the running sequence.
+    ///
+    /// The sequence stops before its next tap; it does not interrupt a tap that
+    /// has already been dispatched.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    /// Returns `true` once [`cancel`](Self::cancel) has been called.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+}
+
+/// A high-level, meaning-carrying feedback event.
+///
+/// Mirrors the SwiftUI `SensoryFeedback` vocabulary so frontends can call
+/// meaningful names instead of reasoning about raw patterns. See
+/// [`HapticFeedbackManager::perform_semantic`] for how each variant is
+/// rendered on the two backends.
+#[derive(Debug, Clone, Copy)]
+pub enum SemanticFeedback {
+    /// A value changed through direct selection, e.g. scrubbing a picker.
+    Selection,
+    /// An operation completed successfully.
+    Success,
+    /// An operation completed but warrants attention.
+    Warning,
+    /// An operation failed.
+    Error,
+    /// A UI element collided with or settled against a boundary.
+    Impact {
+        /// How forceful the impact feels.
+        weight: ImpactWeight,
+    },
+    /// Something aligned with a guide (maps directly to
+    /// [`HapticPattern::Alignment`]).
+    Alignment,
+    /// A discrete value stepped up or down (maps directly to
+    /// [`HapticPattern::LevelChange`]).
+    LevelChange,
+}
+
+impl SemanticFeedback {
+    /// The closest built-in [`HapticPattern`] for this event, used when only
+    /// `NSHapticFeedbackManager` is available.
+    fn builtin_pattern(self) -> HapticPattern {
+        match self {
+            SemanticFeedback::Alignment => HapticPattern::Alignment,
+            SemanticFeedback::LevelChange
+            | SemanticFeedback::Success
+            | SemanticFeedback::Warning
+            | SemanticFeedback::Error => HapticPattern::LevelChange,
+            SemanticFeedback::Selection | SemanticFeedback::Impact { .. } => HapticPattern::Generic,
+        }
+    }
+}
+
+/// How forceful a [`SemanticFeedback::Impact`] feels.
+#[derive(Debug, Clone, Copy)]
+pub enum ImpactWeight {
+    /// A light, subtle tap.
+    Light,
+    /// A medium tap.
+    Medium,
+    /// A heavy, pronounced tap.
+    Heavy,
+}
+
+impl ImpactWeight {
+    /// The CoreHaptics intensity this weight maps to.
+    pub(crate) fn intensity(self) -> f32 {
+        match self {
+            ImpactWeight::Light => 0.4,
+            ImpactWeight::Medium => 0.7,
+            ImpactWeight::Heavy => 1.0,
+        }
+    }
 }
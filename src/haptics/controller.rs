@@ -0,0 +1,114 @@
+//! Game-controller haptics via the GameController framework.
+//!
+//! Beyond the trackpad, macOS 11+ can drive rumble on connected game
+//! controllers (DualSense, Xbox, MFi) through `GCController` and its
+//! `GCDeviceHaptics`. Each controller vends one or more engines keyed by a
+//! *locality* — the whole body, a single handle, or a trigger — and each
+//! engine is an ordinary `CHHapticEngine`, so playback reuses the
+//! [`core`](super::core) backend.
+//!
+//! Use [`controller_haptics_supported`] to decide whether to offer controller
+//! rumble, [`list_controllers`] to enumerate what is connected, and
+//! [`play_controller_haptic`] to play on a specific controller and locality.
+//!
+//! See [Apple's documentation](https://developer.apple.com/documentation/gamecontroller).
+
+use objc2_game_controller::{
+    GCController, GCHapticsLocality, GCHapticsLocalityAll, GCHapticsLocalityDefault,
+    GCHapticsLocalityLeftHandle, GCHapticsLocalityLeftTrigger, GCHapticsLocalityRightHandle,
+    GCHapticsLocalityRightTrigger,
+};
+
+pub use crate::ControllerInfo;
+
+/// Returns `true` when at least one connected controller can play haptics.
+///
+/// Controller haptics require macOS 11+; a `false` result also covers older
+/// systems and the no-controller-connected case.
+pub fn controller_haptics_supported() -> bool {
+    list_controllers().iter().any(|c| c.supports_haptics)
+}
+
+/// Enumerates the currently connected game controllers.
+pub fn list_controllers() -> Vec<ControllerInfo> {
+    let controllers = unsafe { GCController::controllers() };
+    controllers
+        .iter()
+        .enumerate()
+        .map(|(index, controller)| ControllerInfo {
+            id: index.to_string(),
+            name: unsafe { controller.vendorName() }
+                .map(|name| name.to_string())
+                .unwrap_or_else(|| "Unknown Controller".to_string()),
+            supports_haptics: unsafe { controller.haptics() }.is_some(),
+        })
+        .collect()
+}
+
+/// Upper bound, in milliseconds, on a single rumble. A controller engine is
+/// held alive for the requested duration, so an unbounded value from the
+/// frontend would pin a thread indefinitely; clamp to a generous ceiling.
+const MAX_RUMBLE_MS: u64 = 10_000;
+
+/// Plays rumble on a connected controller for `duration_ms` milliseconds.
+///
+/// `controller_id` is an identifier returned by [`list_controllers`];
+/// `intensity` and `sharpness` are clamped to `0.0..=1.0` by the
+/// [`core`](super::core) backend, `duration_ms` is capped at [`MAX_RUMBLE_MS`],
+/// and `locality` selects which engine to drive (see [`locality_from_str`] for
+/// the accepted names).
+///
+/// Returns a descriptive error if the controller is unknown, has no haptics
+/// engine, or does not support the requested locality.
+pub fn play_controller_haptic(
+    controller_id: &str,
+    intensity: f32,
+    sharpness: f32,
+    duration_ms: u64,
+    locality: &str,
+) -> Result<(), String> {
+    let index: usize = controller_id
+        .parse()
+        .map_err(|_| format!("invalid controller id \"{controller_id}\""))?;
+
+    let controllers = unsafe { GCController::controllers() };
+    let controller = controllers
+        .iter()
+        .nth(index)
+        .ok_or_else(|| format!("no controller with id \"{controller_id}\""))?;
+
+    let haptics = unsafe { controller.haptics() }
+        .ok_or_else(|| "controller does not support haptics".to_string())?;
+
+    let locality = locality_from_str(locality)?;
+    let engine = unsafe { haptics.createEngineWithLocality(locality) }.ok_or_else(|| {
+        "controller has no haptics engine for the requested locality".to_string()
+    })?;
+
+    // `play_continuous` keeps the engine and its player alive for `duration_ms`
+    // before returning, so binding the engine here is enough to let the rumble
+    // run for its full duration rather than being stopped the instant the
+    // engine is dropped.
+    let engine = super::core::start_external(engine)?;
+    let result = engine.play_continuous(duration_ms.min(MAX_RUMBLE_MS), intensity, sharpness);
+    drop(engine);
+    result
+}
+
+/// Resolves a locality name to its `GCHapticsLocality` constant.
+///
+/// Accepts `default`, `all`, `leftHandle`, `rightHandle`, `leftTrigger`, and
+/// `rightTrigger` (case-insensitive). `left`/`right` are accepted as aliases
+/// for the matching handle.
+pub fn locality_from_str(locality: &str) -> Result<&'static GCHapticsLocality, String> {
+    let locality = match locality.to_ascii_lowercase().as_str() {
+        "default" => unsafe { GCHapticsLocalityDefault },
+        "all" => unsafe { GCHapticsLocalityAll },
+        "lefthandle" | "left" => unsafe { GCHapticsLocalityLeftHandle },
+        "righthandle" | "right" => unsafe { GCHapticsLocalityRightHandle },
+        "lefttrigger" => unsafe { GCHapticsLocalityLeftTrigger },
+        "righttrigger" => unsafe { GCHapticsLocalityRightTrigger },
+        other => return Err(format!("unknown haptics locality \"{other}\"")),
+    };
+    Ok(locality)
+}
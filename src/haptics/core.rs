@@ -0,0 +1,555 @@
+//! CoreHaptics backend for custom intensity/sharpness patterns.
+//!
+//! [`HapticFeedbackManager`](super::HapticFeedbackManager) is limited to the
+//! three fixed patterns that `NSHapticFeedbackManager` exposes. CoreHaptics
+//! (`CoreHaptics.framework`, available macOS 10.15+) instead lets you describe
+//! a waveform out of individual events, each with an explicit `intensity` and
+//! `sharpness`. [`CoreHapticsEngine`] wraps the `CHHapticEngine` lifecycle and
+//! offers convenience methods for the two most common cases: a single
+//! transient tap and a sustained continuous buzz.
+//!
+//! Use [`is_core_haptics_supported`] to decide whether to prefer this backend;
+//! when it returns `false`, fall back to the `NSHapticFeedbackManager` path.
+//!
+//! See [Apple's documentation](https://developer.apple.com/documentation/corehaptics).
+
+use std::path::Path;
+use std::time::Duration;
+
+use serde::Deserialize;
+
+use objc2::rc::Retained;
+use objc2::runtime::ProtocolObject;
+use objc2_core_haptics::{
+    CHHapticEngine, CHHapticEvent, CHHapticEventParameter, CHHapticEventParameterID,
+    CHHapticEventType, CHHapticPattern, CHHapticPatternPlayer,
+};
+use objc2_foundation::{NSArray, NSError};
+
+/// Returns `true` when the current hardware can play CoreHaptics patterns.
+///
+/// This corresponds to
+/// `CHHapticEngine.capabilitiesForHardware().supportsHaptics`. When it returns
+/// `false`, callers should fall back to
+/// [`HapticFeedbackManager`](super::HapticFeedbackManager).
+pub fn is_core_haptics_supported() -> bool {
+    let capabilities = unsafe { CHHapticEngine::capabilitiesForHardware() };
+    unsafe { capabilities.supportsHaptics() }
+}
+
+/// Convert an `NSError` into the descriptive `String` used throughout the
+/// command layer.
+fn describe(error: &NSError) -> String {
+    unsafe { error.localizedDescription() }.to_string()
+}
+
+/// A started `CHHapticEngine` ready to play patterns.
+///
+/// The engine is created and started on construction and stopped when the
+/// value is dropped. Building events requires the engine to be running, so keep
+/// the instance alive for as long as you intend to play haptics.
+///
+/// # Example
+/// ```rust,no_run
+/// # use tauri_macos_haptics::haptics::core::*;
+/// if is_core_haptics_supported() {
+///     let engine = CoreHapticsEngine::new()?;
+///     // A crisp, strong tap.
+///     engine.play_transient(1.0, 1.0)?;
+///     // A soft half-second rumble.
+///     engine.play_continuous(500, 0.4, 0.2)?;
+/// }
+/// # Ok::<(), String>(())
+/// ```
+pub struct CoreHapticsEngine {
+    engine: Retained<CHHapticEngine>,
+}
+
+impl CoreHapticsEngine {
+    /// Creates and starts a new CoreHaptics engine.
+    ///
+    /// Returns a descriptive error if the engine cannot be created or started —
+    /// most commonly because the hardware does not support haptics. Check
+    /// [`is_core_haptics_supported`] first to avoid that case.
+    pub fn new() -> Result<Self, String> {
+        let engine = unsafe { CHHapticEngine::initAndReturnError(CHHapticEngine::alloc()) }
+            .map_err(|e| describe(&e))?;
+        unsafe { engine.startAndReturnError() }.map_err(|e| describe(&e))?;
+        Ok(Self { engine })
+    }
+
+    /// Plays a single momentary tap with the given `intensity` and `sharpness`.
+    ///
+    /// Both parameters are clamped to the `0.0..=1.0` range CoreHaptics
+    /// expects: `intensity` controls how strong the tap feels, `sharpness` how
+    /// crisp versus dull it is.
+    pub fn play_transient(&self, intensity: f32, sharpness: f32) -> Result<(), String> {
+        let event = self.transient_event(intensity, sharpness, 0.0);
+        self.play(&[event])
+    }
+
+    /// Extra time, in seconds, that the player is kept alive past a transient
+    /// event's relative time. A transient is momentary, but CoreHaptics stops
+    /// playback the instant the player is released, so a short tail ensures the
+    /// pulse is dispatched before teardown.
+    const TRANSIENT_TAIL_SECS: f64 = 0.1;
+
+    /// Plays a sustained buzz of `duration_ms` milliseconds.
+    ///
+    /// As with [`play_transient`](Self::play_transient), `intensity` and
+    /// `sharpness` are clamped to `0.0..=1.0`.
+    pub fn play_continuous(
+        &self,
+        duration_ms: u64,
+        intensity: f32,
+        sharpness: f32,
+    ) -> Result<(), String> {
+        let event = self.continuous_event(intensity, sharpness, 0.0, duration_ms);
+        self.play(&[event])
+    }
+
+    /// Builds a transient [`CHHapticEvent`] starting at `relative_time`.
+    pub(crate) fn transient_event(
+        &self,
+        intensity: f32,
+        sharpness: f32,
+        relative_time: f64,
+    ) -> TimedEvent {
+        let parameters = event_parameters(intensity, sharpness);
+        let event = unsafe {
+            CHHapticEvent::initWithEventType_parameters_relativeTime(
+                CHHapticEvent::alloc(),
+                CHHapticEventType::HapticTransient,
+                &parameters,
+                relative_time,
+            )
+        };
+        TimedEvent {
+            event,
+            end: relative_time + Self::TRANSIENT_TAIL_SECS,
+        }
+    }
+
+    /// Builds a continuous [`CHHapticEvent`] of `duration_ms` starting at
+    /// `relative_time`.
+    pub(crate) fn continuous_event(
+        &self,
+        intensity: f32,
+        sharpness: f32,
+        relative_time: f64,
+        duration_ms: u64,
+    ) -> TimedEvent {
+        let duration_secs = duration_ms as f64 / 1000.0;
+        let parameters = event_parameters(intensity, sharpness);
+        let event = unsafe {
+            CHHapticEvent::initWithEventType_parameters_relativeTime_duration(
+                CHHapticEvent::alloc(),
+                CHHapticEventType::HapticContinuous,
+                &parameters,
+                relative_time,
+                duration_secs,
+            )
+        };
+        TimedEvent {
+            event,
+            end: relative_time + duration_secs,
+        }
+    }
+
+    /// Assembles a one-shot pattern from `events` and runs it through a freshly
+    /// created player, keeping the player alive until the pattern has finished.
+    pub(crate) fn play(&self, events: &[TimedEvent]) -> Result<(), String> {
+        let duration = events
+            .iter()
+            .map(|e| e.end)
+            .fold(0.0_f64, f64::max)
+            .max(0.0);
+        let objc_events: Vec<_> = events.iter().map(|e| e.event.clone()).collect();
+        let objc_events = NSArray::from_retained_slice(&objc_events);
+        let pattern =
+            unsafe { CHHapticPattern::initWithEvents_error(CHHapticPattern::alloc(), &objc_events) }
+                .map_err(|e| describe(&e))?;
+        self.play_pattern(&pattern, Duration::from_secs_f64(duration))
+    }
+
+    /// Creates a player for `pattern`, starts it, and keeps both the player and
+    /// the engine (`self`) alive for `duration` before returning.
+    ///
+    /// CoreHaptics stops playback as soon as the player is released, so a
+    /// pattern with events scheduled at a non-zero `Time` — or a continuous
+    /// event — would otherwise be cut off the instant this function returned.
+    /// Holding the player for the pattern's full span lets it play out.
+    pub(crate) fn play_pattern(
+        &self,
+        pattern: &CHHapticPattern,
+        duration: Duration,
+    ) -> Result<(), String> {
+        let player: Retained<ProtocolObject<dyn CHHapticPatternPlayer>> =
+            unsafe { self.engine.createPlayerWithPattern_error(pattern) }
+                .map_err(|e| describe(&e))?;
+        unsafe { player.startAtTime_error(0.0) }.map_err(|e| describe(&e))?;
+        // Keep `player` (and `self.engine`) retained until playback completes.
+        std::thread::sleep(duration);
+        drop(player);
+        Ok(())
+    }
+}
+
+/// A built CoreHaptics event paired with the time, in seconds relative to the
+/// start of the pattern, at which it finishes.
+///
+/// [`CoreHapticsEngine::play`] uses the latest `end` across a pattern's events
+/// to decide how long the player must be kept alive so nothing is truncated.
+pub(crate) struct TimedEvent {
+    event: Retained<CHHapticEvent>,
+    end: f64,
+}
+
+impl CoreHapticsEngine {
+    /// Renders a [`SemanticFeedback`](super::SemanticFeedback) event as tuned
+    /// CoreHaptics events.
+    ///
+    /// Success, Warning, and Error are short multi-tap sequences; the remaining
+    /// events are single transients with intensity and sharpness chosen to suit
+    /// their meaning.
+    pub fn play_semantic(&self, feedback: super::SemanticFeedback) -> Result<(), String> {
+        use super::SemanticFeedback as S;
+
+        // Each tuple is `(relative_time, intensity, sharpness)`.
+        let taps: &[(f64, f32, f32)] = match feedback {
+            S::Selection => &[(0.0, 0.4, 0.7)],
+            S::Alignment => &[(0.0, 0.35, 0.5)],
+            S::LevelChange => &[(0.0, 0.6, 0.5)],
+            // Two rising taps for a satisfied "done".
+            S::Success => &[(0.0, 0.5, 0.5), (0.12, 0.9, 0.7)],
+            // Two even taps to draw attention.
+            S::Warning => &[(0.0, 0.7, 0.4), (0.15, 0.7, 0.4)],
+            // Three sharp taps for a firm "no".
+            S::Error => &[(0.0, 1.0, 0.9), (0.1, 1.0, 0.9), (0.2, 1.0, 0.9)],
+            S::Impact { weight } => {
+                let event = self.transient_event(weight.intensity(), 0.8, 0.0);
+                return self.play(&[event]);
+            }
+        };
+
+        let events: Vec<_> = taps
+            .iter()
+            .map(|&(time, intensity, sharpness)| self.transient_event(intensity, sharpness, time))
+            .collect();
+        self.play(&events)
+    }
+
+    /// Loads an Apple Haptic Audio Pattern (AHAP) file and plays it.
+    ///
+    /// AHAP files let designers author reusable haptic assets as JSON instead of
+    /// hardcoding parameters in Rust or JavaScript. The file is read, parsed
+    /// with [`parse_ahap`], converted to a `CHHapticPattern`, and played through
+    /// a freshly created player.
+    ///
+    /// Returns a descriptive error if the file cannot be read or is malformed.
+    pub fn play_ahap(&self, path: impl AsRef<Path>) -> Result<(), String> {
+        let contents = std::fs::read_to_string(path.as_ref())
+            .map_err(|e| format!("failed to read AHAP file: {e}"))?;
+        self.play_ahap_json(&contents)
+    }
+
+    /// Parses an AHAP document from a JSON string and plays it.
+    ///
+    /// Use this when the pattern is already in memory (for example, bundled as a
+    /// string asset) rather than on disk.
+    pub fn play_ahap_json(&self, json: &str) -> Result<(), String> {
+        let ahap = parse_ahap(json)?;
+        let events = self.events_from_ahap(&ahap)?;
+        self.play(&events)
+    }
+
+    /// Converts the parsed AHAP pattern into the CoreHaptics events it describes.
+    fn events_from_ahap(&self, ahap: &AhapFile) -> Result<Vec<TimedEvent>, String> {
+        let planned = plan_ahap_events(ahap)?;
+        Ok(planned
+            .into_iter()
+            .map(|event| match event.kind {
+                PlannedEventKind::Transient => {
+                    self.transient_event(event.intensity, event.sharpness, event.time)
+                }
+                PlannedEventKind::Continuous { duration_ms } => {
+                    self.continuous_event(event.intensity, event.sharpness, event.time, duration_ms)
+                }
+            })
+            .collect())
+    }
+}
+
+/// A validated, platform-independent description of an AHAP event, produced by
+/// [`plan_ahap_events`] before any CoreHaptics objects are built.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct PlannedEvent {
+    /// Start time in seconds, relative to the pattern.
+    pub time: f64,
+    /// Intensity in `0.0..=1.0`.
+    pub intensity: f32,
+    /// Sharpness in `0.0..=1.0`.
+    pub sharpness: f32,
+    /// Whether the event is transient or continuous (with a duration).
+    pub kind: PlannedEventKind,
+}
+
+/// The kind of a [`PlannedEvent`].
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum PlannedEventKind {
+    /// A momentary `HapticTransient` event.
+    Transient,
+    /// A sustained `HapticContinuous` event of `duration_ms` milliseconds.
+    Continuous { duration_ms: u64 },
+}
+
+/// Validates a parsed AHAP document and returns the events it describes.
+///
+/// This is the pure, platform-independent half of AHAP playback: it resolves
+/// each event's parameters, rejects a `HapticContinuous` event missing its
+/// required `EventDuration`, rejects unknown `EventType`s, and requires at least
+/// one playable event. `ParameterCurve` (and any future non-`Event`) entries
+/// are skipped.
+pub(crate) fn plan_ahap_events(ahap: &AhapFile) -> Result<Vec<PlannedEvent>, String> {
+    let mut events = Vec::new();
+    for element in &ahap.pattern {
+        // `ParameterCurve` (and any future) elements carry no `Event`; skip
+        // them rather than failing the whole pattern.
+        let Some(event) = &element.event else {
+            continue;
+        };
+
+        let intensity = event.parameter(HAPTIC_INTENSITY).unwrap_or(1.0);
+        let sharpness = event.parameter(HAPTIC_SHARPNESS).unwrap_or(0.5);
+
+        let kind = match event.event_type.as_str() {
+            "HapticTransient" => PlannedEventKind::Transient,
+            "HapticContinuous" => {
+                let duration = event.event_duration.ok_or_else(|| {
+                    "HapticContinuous event is missing required \"EventDuration\"".to_string()
+                })?;
+                PlannedEventKind::Continuous {
+                    duration_ms: (duration * 1000.0) as u64,
+                }
+            }
+            other => {
+                return Err(format!("unsupported AHAP EventType \"{other}\""));
+            }
+        };
+
+        events.push(PlannedEvent {
+            time: event.time,
+            intensity,
+            sharpness,
+            kind,
+        });
+    }
+
+    if events.is_empty() {
+        return Err("AHAP pattern contains no haptic events".to_string());
+    }
+    Ok(events)
+}
+
+/// The `ParameterID` of a haptic-intensity event parameter in an AHAP file.
+const HAPTIC_INTENSITY: &str = "HapticIntensity";
+/// The `ParameterID` of a haptic-sharpness event parameter in an AHAP file.
+const HAPTIC_SHARPNESS: &str = "HapticSharpness";
+
+/// Parses an AHAP document, validating that the required top-level `Pattern`
+/// key is present.
+///
+/// Returns a descriptive error naming the problem when the JSON is invalid or
+/// the structure does not match the AHAP schema.
+pub fn parse_ahap(json: &str) -> Result<AhapFile, String> {
+    serde_json::from_str(json).map_err(|e| format!("invalid AHAP file: {e}"))
+}
+
+/// A parsed AHAP document.
+///
+/// Only the subset of the schema this crate plays is modelled; unknown keys
+/// (for example `Version` or `Metadata`) are ignored.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AhapFile {
+    /// The ordered list of pattern elements (events and parameter curves).
+    #[serde(rename = "Pattern")]
+    pub pattern: Vec<AhapElement>,
+}
+
+/// A single entry in an AHAP `Pattern` array.
+///
+/// Each entry is a dictionary keyed by its kind; this crate currently plays
+/// `Event` entries and tolerates (ignores) `ParameterCurve` entries.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AhapElement {
+    /// The haptic event, when this element describes one.
+    #[serde(rename = "Event")]
+    pub event: Option<AhapEvent>,
+}
+
+/// A haptic event within an AHAP pattern.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AhapEvent {
+    /// When the event fires, in seconds relative to the start of the pattern.
+    #[serde(rename = "Time")]
+    pub time: f64,
+    /// `HapticTransient` or `HapticContinuous`.
+    #[serde(rename = "EventType")]
+    pub event_type: String,
+    /// Duration in seconds; required for `HapticContinuous` events.
+    #[serde(rename = "EventDuration")]
+    pub event_duration: Option<f64>,
+    /// Per-event parameters such as `HapticIntensity` and `HapticSharpness`.
+    #[serde(rename = "EventParameters", default)]
+    pub event_parameters: Vec<AhapParameter>,
+}
+
+impl AhapEvent {
+    /// Returns the value of the parameter with the given `ParameterID`, if present.
+    fn parameter(&self, id: &str) -> Option<f32> {
+        self.event_parameters
+            .iter()
+            .find(|p| p.parameter_id == id)
+            .map(|p| p.parameter_value)
+    }
+}
+
+/// A single `EventParameters` entry.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AhapParameter {
+    /// The parameter identifier, e.g. `HapticIntensity`.
+    #[serde(rename = "ParameterID")]
+    pub parameter_id: String,
+    /// The parameter value, typically in `0.0..=1.0`.
+    #[serde(rename = "ParameterValue")]
+    pub parameter_value: f32,
+}
+
+/// Wraps a `CHHapticEngine` created elsewhere — for example one vended by a
+/// game controller's `GCDeviceHaptics` — and starts it so the usual
+/// `play_*` methods can drive it.
+pub(crate) fn start_external(
+    engine: Retained<CHHapticEngine>,
+) -> Result<CoreHapticsEngine, String> {
+    unsafe { engine.startAndReturnError() }.map_err(|e| describe(&e))?;
+    Ok(CoreHapticsEngine { engine })
+}
+
+impl Drop for CoreHapticsEngine {
+    fn drop(&mut self) {
+        // Stop the engine without waiting for a completion handler; any error is
+        // irrelevant as the value is going away.
+        unsafe { self.engine.stopWithCompletionHandler(None) };
+    }
+}
+
+/// Builds the `[intensity, sharpness]` parameter array shared by every event,
+/// clamping both values to the `0.0..=1.0` range CoreHaptics requires.
+fn event_parameters(intensity: f32, sharpness: f32) -> Retained<NSArray<CHHapticEventParameter>> {
+    let intensity = make_parameter(CHHapticEventParameterID::HapticIntensity, intensity);
+    let sharpness = make_parameter(CHHapticEventParameterID::HapticSharpness, sharpness);
+    NSArray::from_retained_slice(&[intensity, sharpness])
+}
+
+/// Builds a single [`CHHapticEventParameter`], clamping `value` to `0.0..=1.0`.
+fn make_parameter(
+    id: &CHHapticEventParameterID,
+    value: f32,
+) -> Retained<CHHapticEventParameter> {
+    unsafe {
+        CHHapticEventParameter::initWithParameterID_value(
+            CHHapticEventParameter::alloc(),
+            id,
+            value.clamp(0.0, 1.0),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_and_plans_a_valid_pattern() {
+        let json = r#"{
+            "Version": 1,
+            "Pattern": [
+                { "Event": { "Time": 0.0, "EventType": "HapticTransient",
+                    "EventParameters": [
+                        { "ParameterID": "HapticIntensity", "ParameterValue": 0.8 },
+                        { "ParameterID": "HapticSharpness", "ParameterValue": 0.3 }
+                    ] } },
+                { "Event": { "Time": 0.2, "EventType": "HapticContinuous", "EventDuration": 0.5,
+                    "EventParameters": [] } }
+            ]
+        }"#;
+
+        let ahap = parse_ahap(json).expect("valid AHAP");
+        let planned = plan_ahap_events(&ahap).expect("valid plan");
+
+        assert_eq!(planned.len(), 2);
+        assert_eq!(planned[0].kind, PlannedEventKind::Transient);
+        assert_eq!(planned[0].intensity, 0.8);
+        assert_eq!(planned[0].sharpness, 0.3);
+        assert_eq!(
+            planned[1].kind,
+            PlannedEventKind::Continuous { duration_ms: 500 }
+        );
+    }
+
+    #[test]
+    fn defaults_missing_event_parameters() {
+        let json = r#"{ "Pattern": [
+            { "Event": { "Time": 0.0, "EventType": "HapticTransient" } }
+        ] }"#;
+        let ahap = parse_ahap(json).unwrap();
+        let planned = plan_ahap_events(&ahap).unwrap();
+        assert_eq!(planned[0].intensity, 1.0);
+        assert_eq!(planned[0].sharpness, 0.5);
+    }
+
+    #[test]
+    fn rejects_malformed_json() {
+        let err = parse_ahap("{ not valid json").unwrap_err();
+        assert!(err.starts_with("invalid AHAP file:"), "{err}");
+    }
+
+    #[test]
+    fn rejects_missing_pattern_key() {
+        let err = parse_ahap(r#"{ "Version": 1 }"#).unwrap_err();
+        assert!(err.starts_with("invalid AHAP file:"), "{err}");
+    }
+
+    #[test]
+    fn rejects_continuous_event_without_duration() {
+        let json = r#"{ "Pattern": [
+            { "Event": { "Time": 0.0, "EventType": "HapticContinuous" } }
+        ] }"#;
+        let ahap = parse_ahap(json).unwrap();
+        let err = plan_ahap_events(&ahap).unwrap_err();
+        assert_eq!(
+            err,
+            "HapticContinuous event is missing required \"EventDuration\""
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_event_type() {
+        let json = r#"{ "Pattern": [
+            { "Event": { "Time": 0.0, "EventType": "HapticWobble" } }
+        ] }"#;
+        let ahap = parse_ahap(json).unwrap();
+        let err = plan_ahap_events(&ahap).unwrap_err();
+        assert_eq!(err, "unsupported AHAP EventType \"HapticWobble\"");
+    }
+
+    #[test]
+    fn rejects_pattern_with_no_events() {
+        let json = r#"{ "Pattern": [
+            { "ParameterCurve": { "ParameterID": "HapticIntensityControl" } }
+        ] }"#;
+        let ahap = parse_ahap(json).unwrap();
+        let err = plan_ahap_events(&ahap).unwrap_err();
+        assert_eq!(err, "AHAP pattern contains no haptic events");
+    }
+}
@@ -1,3 +1,4 @@
+use serde::Serialize;
 use tauri::{
     Runtime,
     plugin::{Builder, TauriPlugin},
@@ -8,6 +9,26 @@ mod commands;
 #[cfg(target_os = "macos")]
 pub mod haptics;
 
+/// A connected game controller, as reported by
+/// [`haptics::controller::list_controllers`].
+///
+/// Defined at the crate root (rather than inside the macOS-only `haptics`
+/// module) so the `list_controllers` command can name it on every platform.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ControllerInfo {
+    /// Opaque identifier to pass back to
+    /// [`haptics::controller::play_controller_haptic`].
+    ///
+    /// This is the controller's index in the current connection list and is
+    /// only stable while the set of connected controllers does not change.
+    pub id: String,
+    /// Human-readable controller name, e.g. "DualSense Wireless Controller".
+    pub name: String,
+    /// Whether this controller exposes a haptics engine.
+    pub supports_haptics: bool,
+}
+
 /// Initialize the macOS haptics plugin.
 ///
 /// This function should be called in your Tauri app's setup to register the haptic feedback commands.
@@ -31,7 +52,15 @@ pub fn init<R: Runtime>() -> TauriPlugin<R> {
     Builder::new("tauri-macos-haptics")
         .invoke_handler(tauri::generate_handler![
             commands::is_supported,
-            commands::perform
+            commands::perform,
+            commands::perform_sequence,
+            commands::cancel_sequence,
+            commands::perform_ahap,
+            commands::core_haptics_supported,
+            commands::perform_semantic,
+            commands::controller_haptics_supported,
+            commands::list_controllers,
+            commands::play_controller_haptic
         ])
         .build()
 }